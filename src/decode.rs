@@ -0,0 +1,32 @@
+//! Support code for the `Url::decode_*` methods: zero-allocation percent-decoding into a
+//! caller-provided buffer.
+
+use crate::Error;
+
+/// Percent-decodes `input` into `out`, returning the decoded `&str` view of `out`.
+pub(crate) fn percent_decode<'b>(input: &str, out: &'b mut [u8]) -> Result<&'b str, Error> {
+    let bytes = input.as_bytes();
+    let mut read = 0;
+    let mut write = 0;
+    while read < bytes.len() {
+        let byte = if bytes[read] == b'%' {
+            let hex = bytes
+                .get(read + 1..read + 3)
+                .ok_or(Error::InvalidPercentEncoding)?;
+            read += 3;
+            decode_hex_pair(hex).ok_or(Error::InvalidPercentEncoding)?
+        } else {
+            read += 1;
+            bytes[read - 1]
+        };
+        *out.get_mut(write).ok_or(Error::BufferTooSmall)? = byte;
+        write += 1;
+    }
+    core::str::from_utf8(&out[..write]).map_err(|_| Error::InvalidUtf8)
+}
+
+fn decode_hex_pair(hex: &[u8]) -> Option<u8> {
+    let high = (hex[0] as char).to_digit(16)?;
+    let low = (hex[1] as char).to_digit(16)?;
+    Some(((high << 4) | low) as u8)
+}
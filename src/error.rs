@@ -18,4 +18,12 @@ pub enum Error {
     NoScopeIdAfterPercent,
     /// The specified scope ID was either out of range or contained invalid tokens.
     InvalidScopeId,
+    /// The caller-provided output buffer was too small to hold the result.
+    BufferTooSmall,
+    /// An '@' was present, but no host followed it.
+    NoHostAfterUserinfo,
+    /// A '%' was not followed by two valid hex digits.
+    InvalidPercentEncoding,
+    /// The decoded bytes were not valid UTF-8.
+    InvalidUtf8,
 }
@@ -0,0 +1,87 @@
+//! Support code for [`crate::Url::join`]: a small `core::fmt::Write` sink over a caller-provided
+//! buffer, and the RFC 3986 dot-segment removal algorithm.
+
+use core::fmt;
+
+/// Writes formatted output into a caller-provided byte buffer, tracking how much has been
+/// written so far.
+pub(crate) struct Writer<'a> {
+    pub(crate) buf: &'a mut [u8],
+    pub(crate) pos: usize,
+}
+
+impl<'a> Writer<'a> {
+    pub(crate) fn new(buf: &'a mut [u8]) -> Self {
+        Self { buf, pos: 0 }
+    }
+}
+
+impl fmt::Write for Writer<'_> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let bytes = s.as_bytes();
+        let end = self.pos.checked_add(bytes.len()).ok_or(fmt::Error)?;
+        let dst = self.buf.get_mut(self.pos..end).ok_or(fmt::Error)?;
+        dst.copy_from_slice(bytes);
+        self.pos = end;
+        Ok(())
+    }
+}
+
+/// Returns whether `reference` starts with an RFC 3986 `scheme ":"` prefix, which makes it an
+/// absolute reference rather than one to be resolved against a base URL.
+pub(crate) fn reference_has_scheme(reference: &str) -> bool {
+    let prefix_end = reference.find(['/', '?', '#']).unwrap_or(reference.len());
+    let Some(colon) = reference[..prefix_end].find(':') else {
+        return false;
+    };
+    let scheme = &reference[..colon];
+    let mut chars = scheme.chars();
+    matches!(chars.next(), Some(first) if first.is_ascii_alphabetic())
+        && chars.all(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '-' | '.'))
+}
+
+/// Removes `.` and `..` segments from `buf` in place, following RFC 3986 section 5.2.4.
+///
+/// `buf` must hold an absolute path starting with `/`. Returns the new length of the path.
+pub(crate) fn remove_dot_segments(buf: &mut [u8]) -> usize {
+    let len = buf.len();
+    let mut read = 0;
+    let mut write = 0;
+    while read < len {
+        if buf[read..].starts_with(b"/./") {
+            read += 2;
+        } else if buf[read..] == b"/."[..] {
+            buf[write] = b'/';
+            write += 1;
+            read = len;
+        } else if buf[read..].starts_with(b"/../") {
+            read += 3;
+            write = pop_last_segment(buf, write);
+        } else if buf[read..] == b"/.."[..] {
+            write = pop_last_segment(buf, write);
+            buf[write] = b'/';
+            write += 1;
+            read = len;
+        } else {
+            let mut end = read + 1;
+            while end < len && buf[end] != b'/' {
+                end += 1;
+            }
+            buf.copy_within(read..end, write);
+            write += end - read;
+            read = end;
+        }
+    }
+    write
+}
+
+/// Drops the last `/segment` written to `buf[..write]`, returning the new write position.
+fn pop_last_segment(buf: &[u8], write: usize) -> usize {
+    if write == 0 {
+        return 0;
+    }
+    buf[..write - 1]
+        .iter()
+        .rposition(|&b| b == b'/')
+        .unwrap_or(0)
+}